@@ -152,6 +152,7 @@ pub use self::{
     linker::Linker,
     memory::{Memory, MemoryType},
     module::{
+        ExportFunctionIndicesIter,
         ExportType,
         ImportType,
         InstancePre,
@@ -160,7 +161,7 @@ pub use self::{
         ModuleImportsIter,
         Read,
     },
-    store::{AsContext, AsContextMut, Store, StoreContext, StoreContextMut},
+    store::{AsContext, AsContextMut, Store, StoreContext, StoreContextMut, StoreSnapshot},
     table::{Table, TableType},
     value::Value,
 };
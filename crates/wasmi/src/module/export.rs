@@ -152,3 +152,31 @@ impl<'module> Iterator for ModuleExportsIter<'module> {
         })
     }
 }
+
+/// An iterator over the names and function indices of the exported functions of a [`Module`].
+///
+/// Created via [`Module::export_function_indices`].
+#[derive(Debug)]
+pub struct ExportFunctionIndicesIter<'module> {
+    exports: BTreeIter<'module, Box<str>, ExternIdx>,
+}
+
+impl<'module> ExportFunctionIndicesIter<'module> {
+    /// Creates a new [`ExportFunctionIndicesIter`] from the given [`Module`].
+    pub(super) fn new(module: &'module Module) -> Self {
+        Self {
+            exports: module.header.inner.exports.iter(),
+        }
+    }
+}
+
+impl<'module> Iterator for ExportFunctionIndicesIter<'module> {
+    type Item = (&'module str, u32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.exports.find_map(|(name, idx)| match idx {
+            ExternIdx::Func(index) => Some((&**name, index.into_u32())),
+            _ => None,
+        })
+    }
+}
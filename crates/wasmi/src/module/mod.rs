@@ -24,7 +24,14 @@ pub(crate) use self::{
     utils::WasmiValueType,
 };
 pub use self::{
-    export::{ExportType, FuncIdx, MemoryIdx, ModuleExportsIter, TableIdx},
+    export::{
+        ExportFunctionIndicesIter,
+        ExportType,
+        FuncIdx,
+        MemoryIdx,
+        ModuleExportsIter,
+        TableIdx,
+    },
     global::GlobalIdx,
     import::{FuncTypeIdx, ImportName},
     instantiate::{InstancePre, InstantiationError},
@@ -375,6 +382,40 @@ impl Module {
         Some(ty)
     }
 
+    /// Returns an iterator over the names and function indices of all exported functions.
+    ///
+    /// # Note
+    ///
+    /// - The function indices refer to the function index space of the [`Module`]
+    ///   which includes imported functions.
+    /// - The exports are yielded in the lexicographical order of their names.
+    pub fn export_function_indices(&self) -> ExportFunctionIndicesIter<'_> {
+        ExportFunctionIndicesIter::new(self)
+    }
+
+    /// Returns the function index of the function exported under `name`.
+    ///
+    /// Returns `None` if there is no exported function with the name.
+    pub fn get_export_function_index(&self, name: &str) -> Option<u32> {
+        match self.header.inner.exports.get(name)? {
+            ExternIdx::Func(index) => Some(index.into_u32()),
+            _ => None,
+        }
+    }
+
+    /// Returns the name under which the function at `func_index` is exported.
+    ///
+    /// Returns `None` if the function at `func_index` is not exported.
+    ///
+    /// # Note
+    ///
+    /// If the function is exported under multiple names the
+    /// lexicographically smallest name is returned.
+    pub fn get_function_export_name(&self, func_index: u32) -> Option<&str> {
+        self.export_function_indices()
+            .find_map(|(name, index)| (index == func_index).then_some(name))
+    }
+
     /// Returns the [`ExternType`] for a given [`ExternIdx`].
     ///
     /// # Note
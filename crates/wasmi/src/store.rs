@@ -29,7 +29,7 @@ use crate::{
     TableEntity,
     TableIdx,
 };
-use alloc::boxed::Box;
+use alloc::{boxed::Box, vec::Vec};
 use core::{
     fmt::{self, Debug},
     sync::atomic::{AtomicU32, Ordering},
@@ -164,6 +164,29 @@ fn test_store_is_send_sync() {
     };
 }
 
+/// A snapshot of the mutable Wasm state owned by a [`Store`].
+///
+/// This captures the state of all linear memories, tables, global variables
+/// as well as data and element segments of the [`Store`] at the time the
+/// snapshot was taken.
+///
+/// Created via [`Store::snapshot`] and applied via [`Store::restore`].
+#[derive(Debug, Clone)]
+pub struct StoreSnapshot {
+    /// The index of the [`Store`] the snapshot was taken from.
+    store_idx: StoreIdx,
+    /// The snapshotted linear memories.
+    memories: Vec<MemoryEntity>,
+    /// The snapshotted tables.
+    tables: Vec<TableEntity>,
+    /// The snapshotted global variables.
+    globals: Vec<GlobalEntity>,
+    /// The snapshotted data segments.
+    datas: Vec<DataSegmentEntity>,
+    /// The snapshotted element segments.
+    elems: Vec<ElementSegmentEntity>,
+}
+
 /// An error that may be encountered when operating on the [`Store`].
 #[derive(Debug, Clone)]
 pub enum FuelError {
@@ -810,6 +833,44 @@ impl StoreInner {
         self.resolve(object.as_inner(), &self.extern_objects)
     }
 
+    /// Returns a [`StoreSnapshot`] of the mutable Wasm state of the [`StoreInner`].
+    pub fn snapshot(&self) -> StoreSnapshot {
+        fn clone_all<Idx: ArenaIndex, T: Clone>(arena: &Arena<Idx, T>) -> Vec<T> {
+            arena.iter().map(|(_, entity)| entity.clone()).collect()
+        }
+        StoreSnapshot {
+            store_idx: self.store_idx,
+            memories: clone_all(&self.memories),
+            tables: clone_all(&self.tables),
+            globals: clone_all(&self.globals),
+            datas: clone_all(&self.datas),
+            elems: clone_all(&self.elems),
+        }
+    }
+
+    /// Restores the mutable Wasm state of the [`StoreInner`] from the [`StoreSnapshot`].
+    ///
+    /// # Panics
+    ///
+    /// If the [`StoreSnapshot`] does not originate from this [`Store`].
+    pub fn restore(&mut self, snapshot: &StoreSnapshot) {
+        fn restore_all<Idx: ArenaIndex, T: Clone>(arena: &mut Arena<Idx, T>, snapshot: &[T]) {
+            for ((_, entity), snapshot) in arena.iter_mut().zip(snapshot) {
+                entity.clone_from(snapshot);
+            }
+        }
+        assert_eq!(
+            snapshot.store_idx, self.store_idx,
+            "store snapshot does not belong to store {:?}",
+            self.store_idx,
+        );
+        restore_all(&mut self.memories, &snapshot.memories);
+        restore_all(&mut self.tables, &snapshot.tables);
+        restore_all(&mut self.globals, &snapshot.globals);
+        restore_all(&mut self.datas, &snapshot.datas);
+        restore_all(&mut self.elems, &snapshot.elems);
+    }
+
     /// Allocates a new Wasm or host [`FuncEntity`] and returns a [`Func`] reference to it.
     pub fn alloc_func(&mut self, func: FuncEntity) -> Func {
         let idx = self.funcs.alloc(func);
@@ -956,6 +1017,34 @@ impl<T> Store<T> {
         self.inner.fuel.consume_fuel(|_| delta)
     }
 
+    /// Returns a [`StoreSnapshot`] of the current Wasm state of the [`Store`].
+    ///
+    /// The snapshot captures all linear memories, tables, global variables
+    /// as well as data and element segments allocated in the [`Store`] so far.
+    /// It can later be applied via [`Store::restore`] in order to roll back
+    /// the [`Store`] to the captured state and re-run executions from there.
+    ///
+    /// # Note
+    ///
+    /// The user provided host data and the fuel counters are not part of the snapshot.
+    pub fn snapshot(&self) -> StoreSnapshot {
+        self.inner.snapshot()
+    }
+
+    /// Restores the Wasm state of the [`Store`] captured by the [`StoreSnapshot`].
+    ///
+    /// # Note
+    ///
+    /// Entities that have been allocated in the [`Store`] after the [`StoreSnapshot`]
+    /// has been taken, e.g. by instantiating another module, are left untouched.
+    ///
+    /// # Panics
+    ///
+    /// If the [`StoreSnapshot`] has not been taken from this [`Store`].
+    pub fn restore(&mut self, snapshot: &StoreSnapshot) {
+        self.inner.restore(snapshot)
+    }
+
     /// Allocates a new [`TrampolineEntity`] and returns a [`Trampoline`] reference to it.
     pub(super) fn alloc_trampoline(&mut self, func: TrampolineEntity<T>) -> Trampoline {
         let idx = self.trampolines.alloc(func);
@@ -141,7 +141,7 @@ impl TableType {
 }
 
 /// A Wasm table entity.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct TableEntity {
     ty: TableType,
     elements: Vec<UntypedValue>,
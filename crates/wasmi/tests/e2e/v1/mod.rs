@@ -2,5 +2,7 @@ mod fuel_consumption;
 mod fuel_metering;
 mod func;
 mod host_calls_wasm;
+mod module_exports;
 mod resource_limiter;
 mod resumable_call;
+mod store_snapshot;
@@ -0,0 +1,46 @@
+//! Tests for mapping exported function names to function indices.
+
+use wasmi::{Engine, Module};
+
+/// Compiles the `wat` source into a [`Module`].
+fn module(wat: &str) -> Module {
+    let wasm = wat::parse_str(wat).unwrap();
+    Module::new(&Engine::default(), &wasm[..]).unwrap()
+}
+
+const WAT: &str = r#"
+    (module
+        (import "env" "imported" (func $imported))
+        (memory (export "memory") 1)
+        (func $a (export "a"))
+        (func $b (export "c") (export "b"))
+        (func $c)
+        (export "imported" (func $imported))
+    )
+"#;
+
+#[test]
+fn export_function_indices_works() {
+    let module = module(WAT);
+    let indices: Vec<_> = module.export_function_indices().collect();
+    assert_eq!(indices, [("a", 1), ("b", 2), ("c", 2), ("imported", 0)]);
+}
+
+#[test]
+fn get_export_function_index_works() {
+    let module = module(WAT);
+    assert_eq!(module.get_export_function_index("a"), Some(1));
+    assert_eq!(module.get_export_function_index("b"), Some(2));
+    assert_eq!(module.get_export_function_index("imported"), Some(0));
+    assert_eq!(module.get_export_function_index("memory"), None);
+    assert_eq!(module.get_export_function_index("missing"), None);
+}
+
+#[test]
+fn get_function_export_name_works() {
+    let module = module(WAT);
+    assert_eq!(module.get_function_export_name(0), Some("imported"));
+    assert_eq!(module.get_function_export_name(1), Some("a"));
+    assert_eq!(module.get_function_export_name(2), Some("b"));
+    assert_eq!(module.get_function_export_name(3), None);
+}
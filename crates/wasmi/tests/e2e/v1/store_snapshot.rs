@@ -0,0 +1,88 @@
+//! Tests for taking and restoring [`Store`] snapshots.
+
+use wasmi::{Engine, Global, Instance, Linker, Module, Mutability, Store, Value};
+
+/// Instantiates the `wat` module and returns its [`Store`] and [`Instance`].
+fn setup(wat: &str) -> (Store<()>, Instance) {
+    let wasm = wat::parse_str(wat).unwrap();
+    let engine = Engine::default();
+    let mut store = Store::new(&engine, ());
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let instance = Linker::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    (store, instance)
+}
+
+const WAT: &str = r#"
+    (module
+        (memory (export "mem") 1)
+        (global $g (export "g") (mut i32) (i32.const 0))
+        (table (export "table") 1 funcref)
+        (func $f (export "f") (result i32) (i32.const 42))
+        (func (export "step")
+            (global.set $g (i32.add (global.get $g) (i32.const 1)))
+            (i32.store (i32.const 0) (global.get $g))
+            (drop (memory.grow (i32.const 1)))
+            (drop (table.grow (ref.func $f) (i32.const 1)))
+        )
+    )
+"#;
+
+/// Returns the observable state of the module instance defined in [`WAT`].
+fn state(store: &Store<()>, instance: &Instance) -> (i32, u32, u32, [u8; 4]) {
+    let g = instance
+        .get_global(store, "g")
+        .unwrap()
+        .get(store)
+        .i32()
+        .unwrap();
+    let memory = instance.get_memory(store, "mem").unwrap();
+    let table = instance.get_table(store, "table").unwrap();
+    let mut bytes = [0x00_u8; 4];
+    memory.read(store, 0, &mut bytes).unwrap();
+    (
+        g,
+        memory.current_pages(store).into(),
+        table.size(store),
+        bytes,
+    )
+}
+
+#[test]
+fn snapshot_restore_works() {
+    let (mut store, instance) = setup(WAT);
+    let step = instance.get_typed_func::<(), ()>(&store, "step").unwrap();
+    step.call(&mut store, ()).unwrap();
+    let snapshot = store.snapshot();
+    let before = state(&store, &instance);
+    assert_eq!(before, (1, 2, 2, [1, 0, 0, 0]));
+    step.call(&mut store, ()).unwrap();
+    step.call(&mut store, ()).unwrap();
+    assert_eq!(state(&store, &instance), (3, 4, 4, [3, 0, 0, 0]));
+    store.restore(&snapshot);
+    assert_eq!(state(&store, &instance), before);
+    // Re-running from the restored state yields the same results as before.
+    step.call(&mut store, ()).unwrap();
+    assert_eq!(state(&store, &instance), (2, 3, 3, [2, 0, 0, 0]));
+}
+
+#[test]
+fn restore_keeps_entities_allocated_later() {
+    let (mut store, _) = setup(WAT);
+    let snapshot = store.snapshot();
+    let global = Global::new(&mut store, Value::I32(7), Mutability::Var);
+    store.restore(&snapshot);
+    assert_eq!(global.get(&store).i32(), Some(7));
+}
+
+#[test]
+#[should_panic]
+fn restore_from_other_store_panics() {
+    let (store, _) = setup(WAT);
+    let (mut other, _) = setup(WAT);
+    let snapshot = store.snapshot();
+    other.restore(&snapshot);
+}